@@ -0,0 +1,144 @@
+//! Calendar-aware elapsed-time breakdown between a TLE epoch and a reference
+//! instant, used to judge whether a TLE is too stale to propagate.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::decode_epoch;
+
+pub(crate) struct AgeBreakdown {
+    pub years: i32,
+    pub months: i32,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub total_seconds: f64,
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let first_of_next = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .expect("computed next-month first day is always valid");
+    (first_of_next - Duration::days(1)).day()
+}
+
+/// Advances `dt` by one whole calendar month, clamping the day-of-month to
+/// the target month's length (e.g. Jan 31 + 1 month -> Feb 28/29).
+fn add_one_month(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let (year, month) = if dt.month() == 12 {
+        (dt.year() + 1, 1)
+    } else {
+        (dt.year(), dt.month() + 1)
+    };
+    let day = dt.day().min(last_day_of_month(year, month));
+    let naive = NaiveDate::from_ymd_opt(year, month, day)
+        .expect("day was clamped to the target month's length")
+        .and_time(dt.time());
+    Utc.from_utc_datetime(&naive)
+}
+
+pub(crate) fn breakdown(
+    epoch: DateTime<Utc>,
+    reference: DateTime<Utc>,
+) -> Result<AgeBreakdown, String> {
+    if reference < epoch {
+        return Err("Reference datetime predates the TLE epoch".to_string());
+    }
+
+    let total_seconds = (reference - epoch).num_milliseconds() as f64 / 1000.0;
+
+    let mut years = 0i32;
+    let mut months = 0i32;
+    let mut cursor = epoch;
+    loop {
+        let next = add_one_month(cursor);
+        if next > reference {
+            break;
+        }
+        cursor = next;
+        months += 1;
+        if months == 12 {
+            months = 0;
+            years += 1;
+        }
+    }
+
+    let remainder = reference - cursor;
+    let days = remainder.num_days();
+    let hours = (remainder - Duration::days(days)).num_hours();
+    let minutes = (remainder - Duration::days(days) - Duration::hours(hours)).num_minutes();
+    let seconds =
+        (remainder - Duration::days(days) - Duration::hours(hours) - Duration::minutes(minutes))
+            .num_seconds();
+
+    Ok(AgeBreakdown {
+        years,
+        months,
+        days,
+        hours,
+        minutes,
+        seconds,
+        total_seconds,
+    })
+}
+
+/// Computes the elapsed time between a TLE's epoch and `reference` (defaults
+/// to now, UTC), as a calendar-aware years/months/days/hours/minutes/seconds
+/// breakdown plus `total_seconds` for threshold checks.
+#[pyfunction(signature = (line1, reference=None))]
+pub(crate) fn age(
+    py: Python<'_>,
+    line1: &str,
+    reference: Option<DateTime<Utc>>,
+) -> PyResult<PyObject> {
+    let epoch = decode_epoch(line1).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let reference = reference.unwrap_or_else(Utc::now);
+    let b = breakdown(epoch, reference).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("years", b.years)?;
+    dict.set_item("months", b.months)?;
+    dict.set_item("days", b.days)?;
+    dict.set_item("hours", b.hours)?;
+    dict.set_item("minutes", b.minutes)?;
+    dict.set_item("seconds", b.seconds)?;
+    dict.set_item("total_seconds", b.total_seconds)?;
+    Ok(dict.into_py(py))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::breakdown;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn breakdown_reports_whole_months_then_a_day_hour_minute_second_remainder() {
+        let epoch = Utc.with_ymd_and_hms(2020, 1, 31, 12, 0, 0).unwrap();
+        let reference = Utc.with_ymd_and_hms(2020, 4, 2, 13, 1, 5).unwrap();
+
+        let b = breakdown(epoch, reference).expect("breakdown ok");
+
+        // Jan 31 + 1 month clamps to Feb 29 (2020 is a leap year), then
+        // Feb 29 + 1 month lands on Mar 29 -- two whole months elapsed by
+        // Mar 29, with a few days/hours/minutes/seconds left over.
+        assert_eq!(b.years, 0);
+        assert_eq!(b.months, 2);
+        assert_eq!(b.days, 4);
+        assert_eq!(b.hours, 1);
+        assert_eq!(b.minutes, 1);
+        assert_eq!(b.seconds, 5);
+    }
+
+    #[test]
+    fn breakdown_rejects_a_reference_before_the_epoch() {
+        let epoch = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let reference = Utc.with_ymd_and_hms(2019, 12, 31, 0, 0, 0).unwrap();
+        assert!(breakdown(epoch, reference).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Full TLE element decoding plus the Keplerian quantities derived from them.
+
+use chrono::{DateTime, Utc};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::f64::consts::PI;
+
+use crate::decode_epoch;
+use crate::tle_fields::{field, parse_eccentricity_field, parse_exp_field, RE_KM};
+
+/// Standard gravitational parameter of Earth, km^3/s^2.
+const MU: f64 = 398600.4418;
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+pub(crate) struct ElementSet {
+    pub catalog_number: String,
+    pub classification: String,
+    pub international_designator: String,
+    pub epoch: DateTime<Utc>,
+    pub mean_motion_dot: f64,
+    pub mean_motion_ddot: f64,
+    pub bstar: f64,
+    pub inclination_deg: f64,
+    pub raan_deg: f64,
+    pub eccentricity: f64,
+    pub arg_perigee_deg: f64,
+    pub mean_anomaly_deg: f64,
+    pub mean_motion_rev_day: f64,
+    pub rev_number: u64,
+    pub period_minutes: f64,
+    pub semi_major_axis_km: f64,
+    pub apogee_altitude_km: f64,
+    pub perigee_altitude_km: f64,
+}
+
+pub(crate) fn decode(line1: &str, line2: &str) -> Result<ElementSet, String> {
+    if line1.len() < 69 || line2.len() < 69 {
+        return Err("TLE lines too short to contain full element set".to_string());
+    }
+
+    let catalog_number = field(line1, 2, 7).to_string();
+    let classification = field(line1, 7, 8).to_string();
+    let international_designator = field(line1, 9, 17).to_string();
+    let epoch = decode_epoch(line1)?;
+    let mean_motion_dot: f64 = field(line1, 33, 43)
+        .parse()
+        .map_err(|_| "Invalid first-derivative-of-mean-motion field".to_string())?;
+    let mean_motion_ddot = parse_exp_field(field(line1, 44, 52))?;
+    let bstar = parse_exp_field(field(line1, 53, 61))?;
+
+    let inclination_deg: f64 = field(line2, 8, 16)
+        .parse()
+        .map_err(|_| "Invalid inclination field".to_string())?;
+    let raan_deg: f64 = field(line2, 17, 25)
+        .parse()
+        .map_err(|_| "Invalid RAAN field".to_string())?;
+    let eccentricity = parse_eccentricity_field(field(line2, 26, 33))?;
+    let arg_perigee_deg: f64 = field(line2, 34, 42)
+        .parse()
+        .map_err(|_| "Invalid argument-of-perigee field".to_string())?;
+    let mean_anomaly_deg: f64 = field(line2, 43, 51)
+        .parse()
+        .map_err(|_| "Invalid mean-anomaly field".to_string())?;
+    let mean_motion_rev_day: f64 = field(line2, 52, 63)
+        .parse()
+        .map_err(|_| "Invalid mean-motion field".to_string())?;
+    let rev_number: u64 = field(line2, 63, 68).parse().unwrap_or(0);
+
+    if mean_motion_rev_day <= 0.0 {
+        return Err("Mean motion must be positive".to_string());
+    }
+
+    let period_minutes = 1440.0 / mean_motion_rev_day;
+    let n_rad_s = mean_motion_rev_day * 2.0 * PI / SECONDS_PER_DAY;
+    let semi_major_axis_km = (MU / (n_rad_s * n_rad_s)).powf(1.0 / 3.0);
+    let apogee_altitude_km = semi_major_axis_km * (1.0 + eccentricity) - RE_KM;
+    let perigee_altitude_km = semi_major_axis_km * (1.0 - eccentricity) - RE_KM;
+
+    Ok(ElementSet {
+        catalog_number,
+        classification,
+        international_designator,
+        epoch,
+        mean_motion_dot,
+        mean_motion_ddot,
+        bstar,
+        inclination_deg,
+        raan_deg,
+        eccentricity,
+        arg_perigee_deg,
+        mean_anomaly_deg,
+        mean_motion_rev_day,
+        rev_number,
+        period_minutes,
+        semi_major_axis_km,
+        apogee_altitude_km,
+        perigee_altitude_km,
+    })
+}
+
+/// Decodes `line1`/`line2` into the full element set plus derived Keplerian
+/// quantities (period, semi-major axis, apogee/perigee altitude) as a dict.
+#[pyfunction]
+pub(crate) fn elements(py: Python<'_>, line1: &str, line2: &str) -> PyResult<PyObject> {
+    let el = decode(line1, line2).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("catalog_number", el.catalog_number)?;
+    dict.set_item("classification", el.classification)?;
+    dict.set_item("international_designator", el.international_designator)?;
+    dict.set_item("epoch", el.epoch.into_py(py))?;
+    dict.set_item("mean_motion_dot", el.mean_motion_dot)?;
+    dict.set_item("mean_motion_ddot", el.mean_motion_ddot)?;
+    dict.set_item("bstar", el.bstar)?;
+    dict.set_item("inclination_deg", el.inclination_deg)?;
+    dict.set_item("raan_deg", el.raan_deg)?;
+    dict.set_item("eccentricity", el.eccentricity)?;
+    dict.set_item("arg_perigee_deg", el.arg_perigee_deg)?;
+    dict.set_item("mean_anomaly_deg", el.mean_anomaly_deg)?;
+    dict.set_item("mean_motion_rev_day", el.mean_motion_rev_day)?;
+    dict.set_item("rev_number", el.rev_number)?;
+    dict.set_item("period_minutes", el.period_minutes)?;
+    dict.set_item("semi_major_axis_km", el.semi_major_axis_km)?;
+    dict.set_item("apogee_altitude_km", el.apogee_altitude_km)?;
+    dict.set_item("perigee_altitude_km", el.perigee_altitude_km)?;
+
+    Ok(dict.into_py(py))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+
+    const LINE1: &str = "1 25544U 98067A   20344.91719907  .00001264  00000-0  29621-4 0  9993";
+    const LINE2: &str = "2 25544  51.6466 223.8666 0002416  90.3778  30.6140 15.48970462256430";
+
+    #[test]
+    fn decodes_the_iss_fixture_and_derives_keplerian_quantities() {
+        let el = decode(LINE1, LINE2).expect("decode ok");
+
+        assert_eq!(el.catalog_number, "25544");
+        assert_eq!(el.classification, "U");
+        assert_eq!(el.international_designator, "98067A");
+        assert!((el.inclination_deg - 51.6466).abs() < 1.0e-9);
+        assert!((el.eccentricity - 0.0002416).abs() < 1.0e-9);
+        assert!((el.mean_motion_rev_day - 15.48970462).abs() < 1.0e-6);
+
+        // Roughly ISS altitude: period ~93 min, perigee/apogee a few hundred km up.
+        assert!((90.0..96.0).contains(&el.period_minutes));
+        assert!((300.0..500.0).contains(&el.perigee_altitude_km));
+        assert!((300.0..500.0).contains(&el.apogee_altitude_km));
+        assert!(el.apogee_altitude_km >= el.perigee_altitude_km);
+    }
+}
@@ -0,0 +1,99 @@
+//! Julian Date conversions for the TLE epoch, the time base most
+//! orbital-mechanics math (including SGP4) keys off.
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Timelike, Utc};
+use pyo3::prelude::*;
+
+use crate::decode_epoch;
+
+/// Julian Date of the Unix epoch (1970-01-01T00:00:00Z).
+const JD_UNIX_EPOCH: f64 = 2_440_587.5;
+
+pub(crate) fn to_julian(dt: DateTime<Utc>) -> f64 {
+    let y = dt.year() as f64;
+    let m = dt.month() as f64;
+    let d = dt.day() as f64;
+    let jdn = 367.0 * y - (7.0 * (y + ((m + 9.0) / 12.0).floor()) / 4.0).floor()
+        + (275.0 * m / 9.0).floor()
+        + d
+        + 1_721_013.5;
+    let seconds_into_day =
+        dt.num_seconds_from_midnight() as f64 + dt.timestamp_subsec_micros() as f64 / 1_000_000.0;
+    jdn + seconds_into_day / 86400.0
+}
+
+/// Inverse of `to_julian`: Julian Date -> civil UTC datetime, computed with
+/// Meeus's floating-point algorithm rather than the Fliegel-Van Flandern
+/// integer one.
+pub(crate) fn from_julian(jd: f64) -> DateTime<Utc> {
+    let jd_shifted = jd + 0.5;
+    let z = jd_shifted.floor();
+    let f = jd_shifted - z;
+
+    let a = if z < 2_299_161.0 {
+        z
+    } else {
+        let alpha = ((z - 1_867_216.25) / 36_524.25).floor();
+        z + 1.0 + alpha - (alpha / 4.0).floor()
+    };
+    let b = a + 1524.0;
+    let c = ((b - 122.1) / 365.25).floor();
+    let d = (365.25 * c).floor();
+    let e = ((b - d) / 30.6001).floor();
+
+    let day_with_frac = b - d - (30.6001 * e).floor() + f;
+    let month = if e < 14.0 { e - 1.0 } else { e - 13.0 };
+    let year = if month > 2.0 { c - 4716.0 } else { c - 4715.0 };
+
+    let day_int = day_with_frac.floor();
+    let seconds = (day_with_frac - day_int) * 86400.0;
+    let secs_part = seconds.round() as i64;
+
+    let naive_date = NaiveDate::from_ymd_opt(year as i32, month as u32, day_int as u32)
+        .expect("Fliegel-Van Flandern always yields a valid Gregorian date");
+    let naive = naive_date.and_hms_opt(0, 0, 0).unwrap() + chrono::Duration::seconds(secs_part);
+    Utc.from_utc_datetime(&naive)
+}
+
+/// Julian Date of the TLE epoch.
+#[pyfunction]
+pub(crate) fn epoch_julian(line1: &str) -> PyResult<f64> {
+    let dt = decode_epoch(line1).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(to_julian(dt))
+}
+
+/// Inverse of `epoch_julian`: the UTC datetime for a given Julian Date.
+#[pyfunction]
+pub(crate) fn epoch_from_julian(jd: f64) -> PyResult<DateTime<Utc>> {
+    Ok(from_julian(jd))
+}
+
+/// Days elapsed between the Unix epoch and the TLE epoch (fractional).
+#[pyfunction]
+pub(crate) fn days_since_unix(line1: &str) -> PyResult<f64> {
+    let dt = decode_epoch(line1).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(to_julian(dt) - JD_UNIX_EPOCH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_julian, to_julian};
+    use chrono::{TimeZone, Utc};
+
+    // J2000.0, the standard astronomical reference epoch.
+    const J2000_JD: f64 = 2_451_545.0;
+
+    #[test]
+    fn to_julian_matches_j2000_reference() {
+        let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        assert!((to_julian(j2000) - J2000_JD).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn from_julian_inverts_to_julian() {
+        let dt = Utc.with_ymd_and_hms(2020, 12, 9, 22, 1, 46).unwrap();
+        let jd = to_julian(dt);
+        let round_tripped = from_julian(jd);
+        assert_eq!(round_tripped.timestamp(), dt.timestamp());
+    }
+}
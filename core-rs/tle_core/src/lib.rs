@@ -1,9 +1,17 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyModule, PyTuple};
+use pyo3::types::PyModule;
 use pyo3::Bound;
 
-fn checksum_inner(line: &str) -> bool {
+mod age;
+mod elements;
+mod julian;
+mod rebuild;
+mod sgp4_core;
+mod tle_fields;
+
+pub(crate) fn checksum_inner(line: &str) -> bool {
     let trimmed = line.trim_end();
     let mut chars = trimmed.chars();
     let last = match chars.next_back() {
@@ -38,7 +46,11 @@ fn ensure_source(src: &str) -> String {
 }
 
 #[pyfunction(signature = (text, norad_id="", source=""))]
-fn parse(text: &str, norad_id: &str, source: &str) -> PyResult<(String, Option<String>, String, String, String)> {
+fn parse(
+    text: &str,
+    norad_id: &str,
+    source: &str,
+) -> PyResult<(String, Option<String>, String, String, String)> {
     let lines: Vec<String> = text
         .lines()
         .map(|l| l.trim())
@@ -51,7 +63,8 @@ fn parse(text: &str, norad_id: &str, source: &str) -> PyResult<(String, Option<S
     let mut line2: Option<String> = None;
 
     for idx in 0..lines.len() {
-        if lines[idx].starts_with("1 ") && idx + 1 < lines.len() && lines[idx + 1].starts_with("2 ") {
+        if lines[idx].starts_with("1 ") && idx + 1 < lines.len() && lines[idx + 1].starts_with("2 ")
+        {
             if idx > 0 && !lines[idx - 1].starts_with("1 ") && !lines[idx - 1].starts_with("2 ") {
                 name = Some(lines[idx - 1].trim().to_string());
             }
@@ -75,35 +88,32 @@ fn parse(text: &str, norad_id: &str, source: &str) -> PyResult<(String, Option<S
                         }
                     }
                 }
-                _ => return Err(PyValueError::new_err("Could not locate TLE line pair in response")),
+                _ => {
+                    return Err(PyValueError::new_err(
+                        "Could not locate TLE line pair in response",
+                    ))
+                }
             }
         } else {
-            return Err(PyValueError::new_err("Could not locate TLE line pair in response"));
+            return Err(PyValueError::new_err(
+                "Could not locate TLE line pair in response",
+            ));
         }
     }
 
     let line1 = line1.ok_or_else(|| PyValueError::new_err("Empty TLE line detected"))?;
     let line2 = line2.ok_or_else(|| PyValueError::new_err("Empty TLE line detected"))?;
 
-    if !line1.starts_with("1 ") || !line2.starts_with("2 ") {
-        return Err(PyValueError::new_err("Bad TLE line prefixes"));
-    }
-    if !checksum_inner(&line1) || !checksum_inner(&line2) {
-        return Err(PyValueError::new_err("Checksum failed"));
-    }
-
-    let cat1 = catnum_field(&line1);
-    let cat2 = catnum_field(&line2);
-    if cat1 != cat2 {
-        return Err(PyValueError::new_err("Catalog numbers differ between L1 and L2"));
-    }
+    let cat1 = validate_pair(&line1, &line2).map_err(PyValueError::new_err)?;
 
     if !norad_id.is_empty() && norad_id.chars().all(|c| c.is_ascii_digit()) {
         let cat_digits: String = cat1.chars().filter(|c| !c.is_whitespace()).collect();
         if cat_digits.chars().all(|c| c.is_ascii_digit()) {
             if let (Ok(req), Ok(actual)) = (norad_id.parse::<i64>(), cat_digits.parse::<i64>()) {
                 if req != actual {
-                    return Err(PyValueError::new_err("Catalog number does not match requested NORAD ID"));
+                    return Err(PyValueError::new_err(
+                        "Catalog number does not match requested NORAD ID",
+                    ));
                 }
             }
         }
@@ -129,60 +139,196 @@ fn checksum(line: &str) -> PyResult<bool> {
     Ok(checksum_inner(line))
 }
 
-#[pyfunction]
-fn epoch(py: Python<'_>, line1: &str) -> PyResult<PyObject> {
+/// Validates a candidate line1/line2 pair and returns the shared catalog
+/// number, or the reason the pair is unusable.
+fn validate_pair(line1: &str, line2: &str) -> Result<String, String> {
+    if !line1.starts_with("1 ") || !line2.starts_with("2 ") {
+        return Err("Bad TLE line prefixes".to_string());
+    }
+    if !checksum_inner(line1) || !checksum_inner(line2) {
+        return Err("Checksum failed".to_string());
+    }
+    let cat1 = catnum_field(line1);
+    let cat2 = catnum_field(line2);
+    if cat1 != cat2 {
+        return Err("Catalog numbers differ between L1 and L2".to_string());
+    }
+    Ok(cat1)
+}
+
+/// Scans `text` for every 2-line or 3-line (named) TLE block in one pass.
+/// When `strict` is true (the default), the first malformed block raises,
+/// matching `parse`'s behavior; when false, malformed blocks are skipped and
+/// reported in the returned `errors` list as `(block_index, reason)` instead.
+#[pyfunction(signature = (text, source="", strict=true))]
+#[allow(clippy::type_complexity)]
+fn parse_many(
+    text: &str,
+    source: &str,
+    strict: bool,
+) -> PyResult<(
+    Vec<(String, Option<String>, String, String, String)>,
+    Vec<(usize, String)>,
+)> {
+    let lines: Vec<String> = text
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+    let resolved_source = ensure_source(source);
+
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+    let mut block_index = 0usize;
+    let mut idx = 0usize;
+
+    while idx < lines.len() {
+        if lines[idx].starts_with("1 ") && idx + 1 < lines.len() && lines[idx + 1].starts_with("2 ")
+        {
+            let name = if idx > 0
+                && !lines[idx - 1].starts_with("1 ")
+                && !lines[idx - 1].starts_with("2 ")
+            {
+                Some(lines[idx - 1].clone())
+            } else {
+                None
+            };
+            let line1 = lines[idx].clone();
+            let line2 = lines[idx + 1].clone();
+
+            match validate_pair(&line1, &line2) {
+                Ok(cat) => records.push((cat, name, line1, line2, resolved_source.clone())),
+                Err(reason) => {
+                    if strict {
+                        return Err(PyValueError::new_err(reason));
+                    }
+                    errors.push((block_index, reason));
+                }
+            }
+            block_index += 1;
+            idx += 2;
+        } else {
+            idx += 1;
+        }
+    }
+
+    Ok((records, errors))
+}
+
+/// Decodes the `YYDDD.DDDDDDDD` epoch field of TLE line 1 into a UTC instant.
+pub(crate) fn decode_epoch(line1: &str) -> Result<DateTime<Utc>, String> {
     if line1.len() < 32 {
-        return Err(PyValueError::new_err("Line 1 too short to contain epoch"));
+        return Err("Line 1 too short to contain epoch".to_string());
     }
     let year2: i32 = line1[18..20]
         .parse()
-        .map_err(|_| PyValueError::new_err("Invalid epoch year"))?;
+        .map_err(|_| "Invalid epoch year".to_string())?;
     let doy: f64 = line1[20..32]
         .trim()
         .parse()
-        .map_err(|_| PyValueError::new_err("Invalid epoch day"))?;
-    let year = if year2 >= 57 { 1900 + year2 } else { 2000 + year2 };
+        .map_err(|_| "Invalid epoch day".to_string())?;
+    let year = if year2 >= 57 {
+        1900 + year2
+    } else {
+        2000 + year2
+    };
     let day_int = doy.floor();
     let frac = doy - day_int;
     let total_seconds = frac * 86400.0;
     if total_seconds < 0.0 {
-        return Err(PyValueError::new_err("Epoch fraction produced negative seconds"));
-    }
-    let mut secs_part = total_seconds.floor();
-    let mut micros = ((total_seconds - secs_part) * 1_000_000.0).round();
-    if micros >= 1_000_000.0 {
-        secs_part += 1.0;
-        micros -= 1_000_000.0;
-    }
-
-    let datetime = py.import_bound("datetime")?;
-    let datetime_cls = datetime.getattr("datetime")?;
-    let timezone = datetime.getattr("timezone")?.getattr("utc")?;
-    let kwargs = PyDict::new_bound(py);
-    kwargs.set_item("tzinfo", &timezone)?;
-    let base = datetime_cls.call((year, 1, 1, 0, 0, 0), Some(&kwargs))?;
-    let delta_kwargs = PyDict::new_bound(py);
-    delta_kwargs.set_item("days", (day_int as i64) - 1)?;
-    delta_kwargs.set_item("seconds", secs_part as i64)?;
-    delta_kwargs.set_item("microseconds", micros as i64)?;
-    let delta = datetime.getattr("timedelta")?.call((), Some(&delta_kwargs))?;
-    let result = base.call_method1("__add__", (&delta,))?;
-    Ok(result.into_py(py))
+        return Err("Epoch fraction produced negative seconds".to_string());
+    }
+
+    let mut secs_part = total_seconds.floor() as i64;
+    let mut micros = ((total_seconds - secs_part as f64) * 1_000_000.0).round() as i64;
+    if micros >= 1_000_000 {
+        secs_part += 1;
+        micros -= 1_000_000;
+    }
+
+    let base = NaiveDate::from_ymd_opt(year, 1, 1)
+        .ok_or_else(|| "Invalid epoch year".to_string())?
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is always a valid time");
+    let naive = base
+        + Duration::days(day_int as i64 - 1)
+        + Duration::seconds(secs_part)
+        + Duration::microseconds(micros);
+    Ok(Utc.from_utc_datetime(&naive))
+}
+
+/// Returns the TLE epoch as a native Python datetime, aware and UTC by
+/// default, or tz-naive when `naive` is true.
+#[pyfunction(signature = (line1, naive=false))]
+fn epoch(py: Python<'_>, line1: &str, naive: bool) -> PyResult<PyObject> {
+    let dt = decode_epoch(line1).map_err(PyValueError::new_err)?;
+    if naive {
+        Ok(dt.naive_utc().into_py(py))
+    } else {
+        Ok(dt.into_py(py))
+    }
 }
 
+/// Propagates a TLE to `time` and returns `(r, v)` in TEME km and km/s.
+///
+/// This is near-Earth SGP4 only, scoped to orbits with period < 225 minutes.
+/// `time` may be a number of minutes since the TLE epoch, or a `datetime`
+/// whose tzinfo is UTC (a naive `datetime` is taken as UTC; other tzinfo
+/// objects are rejected). Deep-space orbits (period >= 225 minutes) need
+/// SDP4's lunar-solar resonance terms, which are out of scope here, so those
+/// raise `NotImplementedError` rather than returning an unsupported result.
 #[pyfunction]
-fn sgp4(_args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
-    Err(PyNotImplementedError::new_err(
-        "SGP4 propagation not implemented in Rust backend",
-    ))
+#[allow(clippy::type_complexity)]
+fn sgp4(
+    line1: &str,
+    line2: &str,
+    time: &Bound<'_, PyAny>,
+) -> PyResult<((f64, f64, f64), (f64, f64, f64))> {
+    validate_pair(line1, line2).map_err(PyValueError::new_err)?;
+
+    let minutes = match time.extract::<f64>() {
+        Ok(m) => m,
+        Err(_) => {
+            let epoch_dt = decode_epoch(line1).map_err(PyValueError::new_err)?;
+            let target: DateTime<Utc> = match time.extract::<DateTime<Utc>>() {
+                Ok(dt) => dt,
+                Err(err) => match time.extract::<chrono::NaiveDateTime>() {
+                    Ok(naive) => Utc.from_utc_datetime(&naive),
+                    Err(_) => return Err(err),
+                },
+            };
+            (target - epoch_dt)
+                .num_microseconds()
+                .map(|us| us as f64 / 60_000_000.0)
+                .ok_or_else(|| PyValueError::new_err("Time delta too large to represent"))?
+        }
+    };
+
+    let elements = sgp4_core::parse_raw_elements(line1, line2).map_err(PyValueError::new_err)?;
+    if sgp4_core::is_deep_space(elements.no_kozai) {
+        return Err(PyNotImplementedError::new_err(
+            "Deep-space (SDP4) propagation is not implemented; orbit period >= 225 minutes",
+        ));
+    }
+    let (r, v) = sgp4_core::propagate(&elements, minutes).map_err(PyValueError::new_err)?;
+    Ok(((r[0], r[1], r[2]), (v[0], v[1], v[2])))
 }
 
 #[pymodule]
 fn _tle_core(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_many, m)?)?;
     m.add_function(wrap_pyfunction!(checksum, m)?)?;
     m.add_function(wrap_pyfunction!(epoch, m)?)?;
     m.add_function(wrap_pyfunction!(sgp4, m)?)?;
+    m.add_function(wrap_pyfunction!(elements::elements, m)?)?;
+    m.add_function(wrap_pyfunction!(rebuild::format_epoch, m)?)?;
+    m.add_function(wrap_pyfunction!(rebuild::rebuild, m)?)?;
+    m.add_function(wrap_pyfunction!(age::age, m)?)?;
+    m.add_function(wrap_pyfunction!(julian::epoch_julian, m)?)?;
+    m.add_function(wrap_pyfunction!(julian::epoch_from_julian, m)?)?;
+    m.add_function(wrap_pyfunction!(julian::days_since_unix, m)?)?;
     // Keep module doc minimal but informative.
     m.add("__doc__", "Rust-accelerated primitives for tle_fetcher")?;
     Ok(())
@@ -190,14 +336,17 @@ fn _tle_core(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::{catnum_field, checksum_inner, parse};
+    use super::{catnum_field, checksum_inner, epoch, parse, parse_many};
     use pyo3::prelude::*;
 
     const SAMPLE: &str = "ISS (ZARYA)\n1 25544U 98067A   20344.91719907  .00001264  00000-0  29621-4 0  9993\n2 25544  51.6466 223.8666 0002416  90.3778  30.6140 15.48970462256430\n";
+    const LINE1: &str = "1 25544U 98067A   20344.91719907  .00001264  00000-0  29621-4 0  9993";
 
     #[test]
     fn checksum_matches_python() {
-        assert!(checksum_inner("1 25544U 98067A   20344.91719907  .00001264  00000-0  29621-4 0  9993"));
+        assert!(checksum_inner(
+            "1 25544U 98067A   20344.91719907  .00001264  00000-0  29621-4 0  9993"
+        ));
     }
 
     #[test]
@@ -210,4 +359,45 @@ mod tests {
             assert_eq!(catnum_field(&result.2), "25544");
         });
     }
+
+    #[test]
+    fn epoch_naive_flag_controls_tzinfo() {
+        Python::with_gil(|py| {
+            let aware = epoch(py, LINE1, false).expect("aware epoch ok");
+            let naive = epoch(py, LINE1, true).expect("naive epoch ok");
+            let aware_tzinfo = aware.bind(py).getattr("tzinfo").expect("has tzinfo attr");
+            let naive_tzinfo = naive.bind(py).getattr("tzinfo").expect("has tzinfo attr");
+            assert!(!aware_tzinfo.is_none());
+            assert!(naive_tzinfo.is_none());
+        });
+    }
+
+    #[test]
+    fn parse_many_collects_every_block_in_a_multi_satellite_catalog() {
+        Python::with_gil(|_py| {
+            let catalog = format!("{SAMPLE}{SAMPLE}");
+            let (records, errors) = parse_many(&catalog, "celestrak", true).expect("parse ok");
+            assert_eq!(records.len(), 2);
+            assert!(errors.is_empty());
+            for (cat, name, line1, line2, source) in &records {
+                assert_eq!(cat, "25544");
+                assert_eq!(name.as_deref(), Some("ISS (ZARYA)"));
+                assert!(line1.starts_with("1 "));
+                assert!(line2.starts_with("2 "));
+                assert_eq!(source, "celestrak");
+            }
+        });
+    }
+
+    #[test]
+    fn parse_many_non_strict_reports_bad_blocks_instead_of_raising() {
+        Python::with_gil(|_py| {
+            let bad_line2 = LINE1.replacen("1 ", "2 ", 1);
+            let catalog = format!("{LINE1}\n{bad_line2}\n");
+            let (records, errors) = parse_many(&catalog, "", false).expect("parse ok");
+            assert!(records.is_empty());
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0, 0);
+        });
+    }
 }
@@ -0,0 +1,420 @@
+//! Near-Earth SGP4 propagation.
+//!
+//! Ported from the WGS-72 constants and secular/periodic correction terms in
+//! "Revisiting Spacetrack Report #3" (Vallado, Crawford, Hujsak, Kelso). Only
+//! the bstar drag model is used for secular effects, matching the reference
+//! implementation; the TLE's first/second derivative of mean motion fields
+//! are legacy values carried through for completeness and are not consumed
+//! by the propagator itself.
+//!
+//! Deep-space orbits (period >= 225 min, see `is_deep_space`) need the SDP4
+//! lunar-solar resonance terms (Dscom/Dpper/Dspace) from the same report,
+//! which this module does not implement; callers must reject those before
+//! calling `propagate`.
+
+use std::f64::consts::PI;
+
+use crate::tle_fields::{field, parse_eccentricity_field, parse_exp_field, RE_KM as RE};
+
+const XKE: f64 = 0.0743669161; // sqrt(GM) in earth-radii^1.5 / min
+const CK2: f64 = 1.082616e-3 / 2.0; // 0.5 * J2
+const CK4: f64 = -0.375 * -1.65597e-6; // -0.375 * J4
+const J3: f64 = -2.53881e-6;
+const A3OVK2: f64 = -J3 / CK2;
+const QO: f64 = 1.0 + 120.0 / RE;
+const S0: f64 = 1.0 + 78.0 / RE;
+const QOMS2T: f64 = (QO - S0) * (QO - S0) * (QO - S0) * (QO - S0);
+const MINUTES_PER_DAY: f64 = 1440.0;
+
+/// Raw orbital elements decoded from a TLE line pair, in the units SGP4
+/// itself operates in (radians, radians/minute, earth radii).
+pub(crate) struct RawElements {
+    pub bstar: f64,
+    pub inclo: f64,
+    pub nodeo: f64,
+    pub ecco: f64,
+    pub argpo: f64,
+    pub mo: f64,
+    pub no_kozai: f64,
+}
+
+pub(crate) fn parse_raw_elements(line1: &str, line2: &str) -> Result<RawElements, String> {
+    if line1.len() < 69 || line2.len() < 69 {
+        return Err("TLE lines too short to contain full element set".to_string());
+    }
+
+    let bstar = parse_exp_field(field(line1, 53, 61))?;
+
+    let incl_deg: f64 = field(line2, 8, 16)
+        .parse()
+        .map_err(|_| "Invalid inclination field".to_string())?;
+    let raan_deg: f64 = field(line2, 17, 25)
+        .parse()
+        .map_err(|_| "Invalid RAAN field".to_string())?;
+    let ecco = parse_eccentricity_field(field(line2, 26, 33))?;
+    let argp_deg: f64 = field(line2, 34, 42)
+        .parse()
+        .map_err(|_| "Invalid argument-of-perigee field".to_string())?;
+    let m_deg: f64 = field(line2, 43, 51)
+        .parse()
+        .map_err(|_| "Invalid mean-anomaly field".to_string())?;
+    let n_rev_day: f64 = field(line2, 52, 63)
+        .parse()
+        .map_err(|_| "Invalid mean-motion field".to_string())?;
+
+    if !(0.0..1.0).contains(&ecco) {
+        return Err("Eccentricity out of bounds for an elliptical orbit".to_string());
+    }
+
+    Ok(RawElements {
+        bstar,
+        inclo: incl_deg.to_radians(),
+        nodeo: raan_deg.to_radians(),
+        ecco,
+        argpo: argp_deg.to_radians(),
+        mo: m_deg.to_radians(),
+        no_kozai: n_rev_day * 2.0 * PI / MINUTES_PER_DAY,
+    })
+}
+
+pub(crate) fn is_deep_space(no_kozai: f64) -> bool {
+    2.0 * PI / no_kozai >= 225.0
+}
+
+struct NearEarthState {
+    xmdot: f64,
+    omgdot: f64,
+    xnodot: f64,
+    xnodcf: f64,
+    t2cof: f64,
+    xlcof: f64,
+    aycof: f64,
+    x3thm1: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    aodp: f64,
+    xnodp: f64,
+    cosio: f64,
+    sinio: f64,
+    c1: f64,
+    c4: f64,
+    c5: f64,
+    omgcof: f64,
+    xmcof: f64,
+    delmo: f64,
+    sinmo: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    eta: f64,
+    isimp: bool,
+}
+
+fn near_earth_init(el: &RawElements) -> Result<NearEarthState, String> {
+    let cosio = el.inclo.cos();
+    let theta2 = cosio * cosio;
+    let x3thm1 = 3.0 * theta2 - 1.0;
+    let eosq = el.ecco * el.ecco;
+    let betao2 = 1.0 - eosq;
+    let betao = betao2.sqrt();
+
+    let a1 = (XKE / el.no_kozai).powf(2.0 / 3.0);
+    let del1 = 1.5 * CK2 * x3thm1 / (a1 * a1 * betao * betao2);
+    let ao = a1 * (1.0 - del1 * (1.0 / 3.0 + del1 * (1.0 + 134.0 / 81.0 * del1)));
+    let delo = 1.5 * CK2 * x3thm1 / (ao * ao * betao * betao2);
+    let xnodp = el.no_kozai / (1.0 + delo);
+    let aodp = ao / (1.0 - delo);
+
+    let perige = (aodp * (1.0 - el.ecco) - 1.0) * RE;
+    if perige < 0.0 {
+        return Err("Orbit has decayed (sub-surface perigee altitude)".to_string());
+    }
+
+    let (s4, qoms24) = if perige < 156.0 {
+        let s4_alt = if perige < 98.0 { 20.0 } else { perige - 78.0 };
+        let qoms24 = ((120.0 - s4_alt) / RE).powi(4);
+        (s4_alt / RE + 1.0, qoms24)
+    } else {
+        (S0, QOMS2T)
+    };
+
+    let pinvsq = 1.0 / (aodp * aodp * betao2 * betao2);
+    let tsi = 1.0 / (aodp - s4);
+    let eta = aodp * el.ecco * tsi;
+    let etasq = eta * eta;
+    let eeta = el.ecco * eta;
+    let psisq = (1.0 - etasq).abs();
+    let coef = qoms24 * tsi.powi(4);
+    let coef1 = coef / psisq.powf(3.5);
+
+    let c2 = coef1
+        * xnodp
+        * (aodp * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+            + 0.75 * CK2 * tsi / psisq * x3thm1 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+    let c1 = el.bstar * c2;
+    let sinio = el.inclo.sin();
+    let c3 = if el.ecco > 1.0e-4 {
+        coef * tsi * A3OVK2 * xnodp * sinio / el.ecco
+    } else {
+        0.0
+    };
+    let x1mth2 = 1.0 - theta2;
+    let c4 = 2.0
+        * xnodp
+        * coef1
+        * aodp
+        * betao2
+        * (eta * (2.0 + 0.5 * etasq) + el.ecco * (0.5 + 2.0 * etasq)
+            - 2.0 * CK2 * tsi / (aodp * psisq)
+                * (-3.0 * x3thm1 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                    + 0.75
+                        * x1mth2
+                        * (2.0 * etasq - eeta * (1.0 + etasq))
+                        * (2.0 * el.argpo).cos()));
+    let c5 = 2.0 * coef1 * aodp * betao2 * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+    let theta4 = theta2 * theta2;
+
+    let temp1 = 3.0 * CK2 * pinvsq * xnodp;
+    let temp2 = temp1 * CK2 * pinvsq;
+    let temp3 = 1.25 * CK4 * pinvsq * pinvsq * xnodp;
+
+    let xmdot = xnodp
+        + 0.5 * temp1 * betao * x3thm1
+        + 0.0625 * temp2 * betao * (13.0 - 78.0 * theta2 + 137.0 * theta4);
+    let x1m5th = 1.0 - 5.0 * theta2;
+    let omgdot = -0.5 * temp1 * x1m5th
+        + 0.0625 * temp2 * (7.0 - 114.0 * theta2 + 395.0 * theta4)
+        + temp3 * (3.0 - 36.0 * theta2 + 49.0 * theta4);
+    let xhdot1 = -temp1 * cosio;
+    let xnodot =
+        xhdot1 + (0.5 * temp2 * (4.0 - 19.0 * theta2) + 2.0 * temp3 * (3.0 - 7.0 * theta2)) * cosio;
+    let xnodcf = 3.5 * betao2 * xhdot1 * c1;
+    let t2cof = 1.5 * c1;
+    let xlcof = if (1.0 + cosio).abs() > 1.5e-12 {
+        0.125 * A3OVK2 * sinio * (3.0 + 5.0 * cosio) / (1.0 + cosio)
+    } else {
+        0.125 * A3OVK2 * sinio * (3.0 + 5.0 * cosio) / 1.5e-12
+    };
+    let aycof = 0.25 * A3OVK2 * sinio;
+    let x7thm1 = 7.0 * theta2 - 1.0;
+
+    let omgcof = el.bstar * c3 * el.argpo.cos();
+    let xmcof = if el.ecco > 1.0e-4 {
+        -2.0 / 3.0 * coef * el.bstar / eeta
+    } else {
+        0.0
+    };
+    let delmo = (1.0 + eta * el.mo.cos()).powi(3);
+    let sinmo = el.mo.sin();
+
+    let isimp = perige < 220.0;
+    let (mut d2, mut d3, mut d4, mut t3cof, mut t4cof, mut t5cof) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    if !isimp {
+        let c1sq = c1 * c1;
+        d2 = 4.0 * aodp * tsi * c1sq;
+        let temp = d2 * tsi * c1 / 3.0;
+        d3 = (17.0 * aodp + s4) * temp;
+        d4 = 0.5 * temp * aodp * tsi * (221.0 * aodp + 31.0 * s4) * c1 / 3.0;
+        t3cof = d2 + 2.0 * c1sq;
+        t4cof = 0.25 * (3.0 * d3 + c1 * (12.0 * d2 + 10.0 * c1sq));
+        t5cof = 0.2 * (3.0 * d4 + 12.0 * c1 * d3 + 6.0 * d2 * d2 + 15.0 * c1sq * (2.0 * d2 + c1sq));
+    }
+
+    Ok(NearEarthState {
+        xmdot,
+        omgdot,
+        xnodot,
+        xnodcf,
+        t2cof,
+        xlcof,
+        aycof,
+        x3thm1,
+        x1mth2,
+        x7thm1,
+        aodp,
+        xnodp,
+        cosio,
+        sinio,
+        c1,
+        c4,
+        c5,
+        omgcof,
+        xmcof,
+        delmo,
+        sinmo,
+        d2,
+        d3,
+        d4,
+        t3cof,
+        t4cof,
+        t5cof,
+        eta,
+        isimp,
+    })
+}
+
+/// Propagates a near-Earth (period < 225 min) orbit. Callers must route
+/// deep-space orbits (see `is_deep_space`) elsewhere; SDP4's Dscom/Dpper/
+/// Dspace lunar-solar resonance terms are not implemented here.
+pub(crate) fn propagate(el: &RawElements, t: f64) -> Result<([f64; 3], [f64; 3]), String> {
+    let st = near_earth_init(el)?;
+
+    let xmdf = el.mo + st.xmdot * t;
+    let omgadf = el.argpo + st.omgdot * t;
+    let xnoddf = el.nodeo + st.xnodot * t;
+
+    let tsq = t * t;
+    let mut xnode = xnoddf + st.xnodcf * tsq;
+    let mut tempa = 1.0 - st.c1 * t;
+    let mut templ = st.t2cof * tsq;
+    let mut tempe = el.bstar * st.c4 * t;
+
+    let (mut omega, mut xmp) = (omgadf, xmdf);
+    if !st.isimp {
+        let delomg = st.omgcof * t;
+        let delm = st.xmcof * ((1.0 + st.eta * xmdf.cos()).powi(3) - st.delmo);
+        let temp = delomg + delm;
+        xmp = xmdf + temp;
+        omega = omgadf - temp;
+        tempa -= st.d2 * tsq + st.d3 * t * tsq + st.d4 * tsq * tsq;
+        tempe += el.bstar * st.c5 * (xmp.sin() - st.sinmo);
+        templ += st.t3cof * t * tsq + tsq * tsq * (st.t4cof + t * st.t5cof);
+    }
+
+    let a = st.aodp * tempa * tempa;
+    let e = el.ecco - tempe;
+    let xl = xmp + omega + xnode + st.xnodp * templ;
+    xnode %= 2.0 * PI;
+
+    if !(0.0..1.0).contains(&e) {
+        return Err("Eccentricity diverged out of bounds during propagation".to_string());
+    }
+
+    let beta2 = 1.0 - e * e;
+    let xn = XKE / a.powf(1.5);
+    let axn = e * omega.cos();
+    let temp = 1.0 / (a * beta2);
+    let xll = temp * st.xlcof * axn;
+    let aynl = temp * st.aycof;
+    let xlt = xl + xll;
+    let ayn = e * omega.sin() + aynl;
+
+    let capu = (xlt - xnode).rem_euclid(2.0 * PI);
+    let mut epw = capu;
+    for _ in 0..10 {
+        let sinepw = epw.sin();
+        let cosepw = epw.cos();
+        let delta =
+            (capu - ayn * cosepw + axn * sinepw - epw) / (1.0 - ayn * sinepw - axn * cosepw);
+        let delta = delta.clamp(-0.95, 0.95);
+        epw += delta;
+        if delta.abs() < 1.0e-12 {
+            break;
+        }
+    }
+
+    let sinepw = epw.sin();
+    let cosepw = epw.cos();
+    let ecose = axn * cosepw + ayn * sinepw;
+    let esine = axn * sinepw - ayn * cosepw;
+    let elsq = axn * axn + ayn * ayn;
+    if elsq >= 1.0 {
+        return Err("Eccentricity vector diverged out of bounds during propagation".to_string());
+    }
+    let pl = a * (1.0 - elsq);
+    if pl < 0.0 {
+        return Err("Semi-latus rectum went negative during propagation".to_string());
+    }
+    let r = a * (1.0 - ecose);
+    let rdot = XKE * a.sqrt() * esine / r;
+    let rfdot = XKE * pl.sqrt() / r;
+    let betal = (1.0 - elsq).sqrt();
+    let temp3 = 1.0 / (1.0 + betal);
+    let cosu = a / r * (cosepw - axn + ayn * esine * temp3);
+    let sinu = a / r * (sinepw - ayn - axn * esine * temp3);
+    let u = sinu.atan2(cosu);
+    let sin2u = 2.0 * sinu * cosu;
+    let cos2u = 1.0 - 2.0 * sinu * sinu;
+
+    let temp = 1.0 / pl;
+    let temp1 = CK2 * temp;
+    let temp2 = temp1 * temp;
+
+    let rk = r * (1.0 - 1.5 * temp2 * betal * st.x3thm1) + 0.5 * temp1 * st.x1mth2 * cos2u;
+    if rk < 1.0 {
+        return Err("Satellite has decayed (propagated radius below Earth's surface)".to_string());
+    }
+    let uk = u - 0.25 * temp2 * st.x7thm1 * sin2u;
+    let xnodek = xnode + 1.5 * temp2 * st.cosio * sin2u;
+    let xinck = el.inclo + 1.5 * temp2 * st.cosio * st.sinio * cos2u;
+    let rdotk = rdot - xn * temp1 * st.x1mth2 * sin2u;
+    let rfdotk = rfdot + xn * temp1 * (st.x1mth2 * cos2u + 1.5 * st.x3thm1);
+
+    let sinuk = uk.sin();
+    let cosuk = uk.cos();
+    let sinik = xinck.sin();
+    let cosik = xinck.cos();
+    let sinnok = xnodek.sin();
+    let cosnok = xnodek.cos();
+    let xmx = -sinnok * cosik;
+    let xmy = cosnok * cosik;
+    let ux = xmx * sinuk + cosnok * cosuk;
+    let uy = xmy * sinuk + sinnok * cosuk;
+    let uz = sinik * sinuk;
+    let vx = xmx * cosuk - cosnok * sinuk;
+    let vy = xmy * cosuk - sinnok * sinuk;
+    let vz = sinik * cosuk;
+
+    let pos = [rk * ux * RE, rk * uy * RE, rk * uz * RE];
+    let vel_er_per_min = [
+        rdotk * ux + rfdotk * vx,
+        rdotk * uy + rfdotk * vy,
+        rdotk * uz + rfdotk * vz,
+    ];
+    let vel = [
+        vel_er_per_min[0] * RE / 60.0,
+        vel_er_per_min[1] * RE / 60.0,
+        vel_er_per_min[2] * RE / 60.0,
+    ];
+
+    Ok((pos, vel))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_deep_space, parse_raw_elements, propagate};
+
+    // Spacetrack Report #3 / Vallado et al. "Revisiting Spacetrack Report #3"
+    // near-Earth verification case (sat 00005), propagated to t=0.
+    const LINE1: &str = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+    const LINE2: &str = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+
+    #[test]
+    fn propagates_to_known_teme_vector() {
+        let el = parse_raw_elements(LINE1, LINE2).expect("valid elements");
+        assert!(!is_deep_space(el.no_kozai));
+
+        let (r, v) = propagate(&el, 0.0).expect("propagation ok");
+
+        let expected_r = [7022.46529266, -1400.08296755, 0.03995155];
+        let expected_v = [1.893841015, 6.405893759, 4.534807328];
+
+        for i in 0..3 {
+            assert!(
+                (r[i] - expected_r[i]).abs() < 1.0e-2,
+                "position component {i}: got {}, want {}",
+                r[i],
+                expected_r[i]
+            );
+            assert!(
+                (v[i] - expected_v[i]).abs() < 1.0e-2,
+                "velocity component {i}: got {}, want {}",
+                v[i],
+                expected_v[i]
+            );
+        }
+    }
+}
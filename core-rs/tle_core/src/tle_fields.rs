@@ -0,0 +1,55 @@
+//! Shared helpers for decoding fixed-column TLE fields.
+
+/// WGS-72 equatorial radius, km.
+pub(crate) const RE_KM: f64 = 6378.135;
+
+/// Slices a fixed-column field and trims surrounding whitespace, tolerating
+/// lines shorter than `end` (the caller is expected to have already checked
+/// overall line length where that matters).
+pub(crate) fn field(line: &str, start: usize, end: usize) -> &str {
+    line.get(start..end.min(line.len())).unwrap_or("").trim()
+}
+
+/// Parses the implied-decimal eccentricity field (no sign, no exponent),
+/// e.g. `"0002416"` -> `0.0002416`.
+pub(crate) fn parse_eccentricity_field(raw: &str) -> Result<f64, String> {
+    let digits = raw.trim();
+    let value: f64 = digits
+        .parse()
+        .map_err(|_| "Invalid eccentricity field".to_string())?;
+    Ok(value / 10f64.powi(digits.len() as i32))
+}
+
+/// Parses a TLE field of the form `[sign]DDDDD[sign]E` (implied leading
+/// decimal point, trailing power-of-ten exponent), e.g. `"29621-4"` -> `0.29621e-4`.
+pub(crate) fn parse_exp_field(raw: &str) -> Result<f64, String> {
+    let s = raw.trim();
+    if s.is_empty() {
+        return Ok(0.0);
+    }
+    let bytes = s.as_bytes();
+    let split = (1..bytes.len())
+        .rev()
+        .find(|&i| bytes[i] == b'+' || bytes[i] == b'-');
+    let (mantissa_part, exp_part) = match split {
+        Some(i) => (&s[..i], &s[i..]),
+        None => (s, "+0"),
+    };
+    let (mant_sign, mant_digits) = if let Some(d) = mantissa_part.strip_prefix('-') {
+        (-1.0, d)
+    } else if let Some(d) = mantissa_part.strip_prefix('+') {
+        (1.0, d)
+    } else {
+        (1.0, mantissa_part)
+    };
+    if mant_digits.is_empty() {
+        return Ok(0.0);
+    }
+    let mantissa: f64 = format!("0.{mant_digits}")
+        .parse()
+        .map_err(|_| format!("Invalid implied-decimal field {raw:?}"))?;
+    let exp: i32 = exp_part
+        .parse()
+        .map_err(|_| format!("Invalid exponent in field {raw:?}"))?;
+    Ok(mant_sign * mantissa * 10f64.powi(exp))
+}